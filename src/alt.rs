@@ -0,0 +1,373 @@
+//! Pixel layouts that aren't the "default" `RGB`/`RGBA`: reversed channel
+//! order, alpha-first order, and single-channel grayscale.
+
+use std;
+use std::fmt;
+use internal::pixel::*;
+use RGB;
+use RGBA;
+
+/// Like `RGB`, but with the components stored in reverse order in memory
+/// (`b,g,r`), matching what some APIs (e.g. Windows bitmaps) expect.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct BGR<T> {
+    pub b: T,
+    pub g: T,
+    pub r: T,
+}
+
+/// Like `RGBA`, but with the components stored in reverse order in memory
+/// (`b,g,r,a`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct BGRA<T, A = T> {
+    pub b: T,
+    pub g: T,
+    pub r: T,
+    pub a: A,
+}
+
+/// Like `RGBA`, but with the alpha channel stored first in memory (`a,r,g,b`).
+///
+/// Several GPU and platform APIs, as well as premultiplied-alpha pipelines,
+/// expect pixels in this alpha-first order rather than `RGBA`'s alpha-last one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct ARGB<T, A = T> {
+    pub a: A,
+    pub r: T,
+    pub g: T,
+    pub b: T,
+}
+
+/// Like `BGRA`, but with the alpha channel stored first in memory (`a,b,g,r`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct ABGR<T, A = T> {
+    pub a: A,
+    pub b: T,
+    pub g: T,
+    pub r: T,
+}
+
+/// A single-channel grayscale pixel.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct Gray<T> {
+    pub v: T,
+}
+
+/// A grayscale pixel with an alpha channel, stored as `v,a`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct GrayAlpha<T, A = T> {
+    pub v: T,
+    pub a: A,
+}
+
+macro_rules! impl_alpha_first {
+    ($ARGB:ident, $n:expr, $fmt:expr, $($field:ident),+) => {
+        impl<T: Clone> $ARGB<T> {
+            #[inline(always)]
+            pub fn new(a: T, $($field: T),+) -> Self {
+                Self {a, $($field),+}
+            }
+
+            /// Iterate over all components (length=4)
+            #[inline(always)]
+            pub fn iter(&self) -> std::iter::Cloned<std::slice::Iter<'_, T>> {
+                self.as_slice().iter().cloned()
+            }
+        }
+
+        impl<T: Copy, B> ComponentMap<$ARGB<B>, T, B> for $ARGB<T> {
+            #[inline(always)]
+            fn map<F>(&self, mut f: F) -> $ARGB<B>
+            where
+                F: FnMut(T) -> B,
+            {
+                $ARGB {
+                    a: f(self.a),
+                    $($field: f(self.$field)),+
+                }
+            }
+        }
+
+        impl<T> ComponentSlice<T> for $ARGB<T> {
+            #[inline(always)]
+            fn as_slice(&self) -> &[T] {
+                unsafe {
+                    std::slice::from_raw_parts(self as *const Self as *const T, $n)
+                }
+            }
+
+            #[inline(always)]
+            fn as_mut_slice(&mut self) -> &mut [T] {
+                unsafe {
+                    std::slice::from_raw_parts_mut(self as *mut Self as *mut T, $n)
+                }
+            }
+        }
+
+        impl<T> ComponentSlice<T> for [$ARGB<T>] {
+            #[inline]
+            fn as_slice(&self) -> &[T] {
+                unsafe {
+                    std::slice::from_raw_parts(self.as_ptr() as *const _, self.len() * $n)
+                }
+            }
+            #[inline]
+            fn as_mut_slice(&mut self) -> &mut [T] {
+                unsafe {
+                    std::slice::from_raw_parts_mut(self.as_ptr() as *mut _, self.len() * $n)
+                }
+            }
+        }
+
+        #[cfg(not(feature = "as-bytes"))]
+        impl<T: Copy + Send + Sync + 'static> ComponentBytes<T> for [$ARGB<T>] {}
+
+        #[cfg(feature = "as-bytes")]
+        impl<T: Copy + Send + Sync + 'static + bytemuck::Pod> ComponentBytes<T> for [$ARGB<T>] {
+            #[inline]
+            fn as_bytes(&self) -> &[u8] {
+                bytemuck::cast_slice(self)
+            }
+            #[inline]
+            fn as_mut_bytes(&mut self) -> &mut [u8] {
+                bytemuck::cast_slice_mut(self)
+            }
+        }
+
+        // `$ARGB<T>` (the homogeneous `A = T` case) is a plain array of `T`
+        // in memory, same as `RGBA`/`BGRA`, so it follows the same
+        // bytemuck-backed `as-bytes` split those use.
+        #[cfg(feature = "as-bytes")]
+        unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for $ARGB<T> {}
+
+        #[cfg(feature = "as-bytes")]
+        unsafe impl<T: bytemuck::Pod> bytemuck::Pod for $ARGB<T> {}
+
+        impl<T: fmt::Display> fmt::Display for $ARGB<T> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, $fmt, self.a, $(self.$field),+)
+            }
+        }
+    }
+}
+
+impl_alpha_first! {ARGB, 4, "argb({},{},{},{})", r, g, b}
+impl_alpha_first! {ABGR, 4, "abgr({},{},{},{})", b, g, r}
+
+impl<T> From<RGBA<T>> for ARGB<T> {
+    #[inline]
+    fn from(px: RGBA<T>) -> Self {
+        ARGB { a: px.a, r: px.r, g: px.g, b: px.b }
+    }
+}
+
+impl<T> From<ARGB<T>> for RGBA<T> {
+    #[inline]
+    fn from(px: ARGB<T>) -> Self {
+        RGBA { r: px.r, g: px.g, b: px.b, a: px.a }
+    }
+}
+
+impl<T> From<RGBA<T>> for ABGR<T> {
+    #[inline]
+    fn from(px: RGBA<T>) -> Self {
+        ABGR { a: px.a, b: px.b, g: px.g, r: px.r }
+    }
+}
+
+impl<T> From<ABGR<T>> for RGBA<T> {
+    #[inline]
+    fn from(px: ABGR<T>) -> Self {
+        RGBA { r: px.r, g: px.g, b: px.b, a: px.a }
+    }
+}
+
+impl<T: Clone> Gray<T> {
+    #[inline(always)]
+    pub fn new(v: T) -> Self {
+        Self { v }
+    }
+}
+
+impl<T: Clone, A> GrayAlpha<T, A> {
+    #[inline(always)]
+    pub fn new(v: T, a: A) -> Self {
+        Self { v, a }
+    }
+}
+
+impl<T> ComponentSlice<T> for Gray<T> {
+    #[inline(always)]
+    fn as_slice(&self) -> &[T] {
+        unsafe {
+            std::slice::from_raw_parts(self as *const Self as *const T, 1)
+        }
+    }
+
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe {
+            std::slice::from_raw_parts_mut(self as *mut Self as *mut T, 1)
+        }
+    }
+}
+
+impl<T> ComponentSlice<T> for GrayAlpha<T> {
+    #[inline(always)]
+    fn as_slice(&self) -> &[T] {
+        unsafe {
+            std::slice::from_raw_parts(self as *const Self as *const T, 2)
+        }
+    }
+
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe {
+            std::slice::from_raw_parts_mut(self as *mut Self as *mut T, 2)
+        }
+    }
+}
+
+impl<T> ComponentSlice<T> for [Gray<T>] {
+    #[inline]
+    fn as_slice(&self) -> &[T] {
+        unsafe {
+            std::slice::from_raw_parts(self.as_ptr() as *const _, self.len())
+        }
+    }
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe {
+            std::slice::from_raw_parts_mut(self.as_ptr() as *mut _, self.len())
+        }
+    }
+}
+
+impl<T> ComponentSlice<T> for [GrayAlpha<T>] {
+    #[inline]
+    fn as_slice(&self) -> &[T] {
+        unsafe {
+            std::slice::from_raw_parts(self.as_ptr() as *const _, self.len() * 2)
+        }
+    }
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe {
+            std::slice::from_raw_parts_mut(self.as_ptr() as *mut _, self.len() * 2)
+        }
+    }
+}
+
+#[cfg(not(feature = "as-bytes"))]
+impl<T: Copy + Send + Sync + 'static> ComponentBytes<T> for [Gray<T>] {}
+
+#[cfg(feature = "as-bytes")]
+impl<T: Copy + Send + Sync + 'static + bytemuck::Pod> ComponentBytes<T> for [Gray<T>] {
+    #[inline]
+    fn as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self)
+    }
+    #[inline]
+    fn as_mut_bytes(&mut self) -> &mut [u8] {
+        bytemuck::cast_slice_mut(self)
+    }
+}
+
+#[cfg(not(feature = "as-bytes"))]
+impl<T: Copy + Send + Sync + 'static> ComponentBytes<T> for [GrayAlpha<T>] {}
+
+#[cfg(feature = "as-bytes")]
+impl<T: Copy + Send + Sync + 'static + bytemuck::Pod> ComponentBytes<T> for [GrayAlpha<T>] {
+    #[inline]
+    fn as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self)
+    }
+    #[inline]
+    fn as_mut_bytes(&mut self) -> &mut [u8] {
+        bytemuck::cast_slice_mut(self)
+    }
+}
+
+// `Gray<T>`/`GrayAlpha<T>` are plain arrays of `T` in memory, so they're
+// byte-castable whenever `T` is, same as the other homogeneous pixel types.
+#[cfg(feature = "as-bytes")]
+unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for Gray<T> {}
+
+#[cfg(feature = "as-bytes")]
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for Gray<T> {}
+
+#[cfg(feature = "as-bytes")]
+unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for GrayAlpha<T> {}
+
+#[cfg(feature = "as-bytes")]
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for GrayAlpha<T> {}
+
+impl<T: fmt::Display> fmt::Display for Gray<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "gray({})", self.v)
+    }
+}
+
+impl<T: fmt::Display, A: fmt::Display> fmt::Display for GrayAlpha<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "gray({},{})", self.v, self.a)
+    }
+}
+
+/// Expands a grayscale+alpha pixel into `RGBA` by broadcasting the luma value
+/// to the red, green and blue channels.
+impl<T: Clone, A> From<GrayAlpha<T, A>> for RGBA<T, A> {
+    #[inline]
+    fn from(px: GrayAlpha<T, A>) -> Self {
+        RGBA { r: px.v.clone(), g: px.v.clone(), b: px.v, a: px.a }
+    }
+}
+
+/// Expands a grayscale pixel into `RGB` by broadcasting the luma value to the
+/// red, green and blue channels.
+impl<T: Clone> From<Gray<T>> for RGB<T> {
+    #[inline]
+    fn from(px: Gray<T>) -> Self {
+        RGB { r: px.v.clone(), g: px.v.clone(), b: px.v }
+    }
+}
+
+#[test]
+fn argb_test() {
+    let px = ARGB::new(1000,1,2,3i32);
+    assert_eq!(px, RGBA::new(1,2,3,1000).into());
+    assert_eq!(RGBA::new(1,2,3,1000), px.into());
+    assert_eq!("argb(1000,1,2,3)", format!("{}", px));
+    assert_eq!(&[1000,1,2,3], px.as_slice());
+}
+
+#[test]
+fn abgr_test() {
+    let px = ABGR::new(1000,3,2,1i32);
+    assert_eq!(px, RGBA::new(1,2,3,1000).into());
+    assert_eq!(RGBA::new(1,2,3,1000), px.into());
+    assert_eq!("abgr(1000,3,2,1)", format!("{}", px));
+}
+
+#[test]
+fn gray_test() {
+    let px = GrayAlpha::new(5u8, 255u8);
+    let rgba: RGBA<u8> = px.into();
+    assert_eq!(RGBA::new(5,5,5,255), rgba);
+    assert_eq!("gray(5,255)", format!("{}", px));
+
+    let rgb: RGB<u8> = Gray::new(7u8).into();
+    assert_eq!(RGB{r:7,g:7,b:7}, rgb);
+
+    let v = [Gray::new(1u8), Gray::new(2u8)];
+    assert_eq!(&[1,2], v.as_bytes());
+
+    let v = [GrayAlpha::new(1u8, 2u8), GrayAlpha::new(3u8, 4u8)];
+    assert_eq!(&[1,2,3,4], v.as_bytes());
+}
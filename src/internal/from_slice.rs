@@ -0,0 +1,64 @@
+use std::slice;
+use RGB;
+use RGBA;
+use alt::BGR;
+use alt::BGRA;
+
+/// Casts a flat slice of color components back into a slice of whole pixels.
+///
+/// This is the opposite of `ComponentSlice`: it lets a buffer that came from
+/// elsewhere (an image decoder, a C FFI call, etc.) be reinterpreted in place
+/// as `&[RGBA<T>]`/`&[BGRA<T>]`/etc. without copying.
+pub trait AsPixels<PixelType> {
+    /// Reinterprets `self` as a slice of pixels.
+    ///
+    /// If `self.len()` isn't a whole multiple of the pixel's component count,
+    /// the trailing partial pixel is dropped rather than aliased.
+    fn as_pixels(&self) -> &[PixelType];
+
+    /// Mutable version of `as_pixels()`.
+    fn as_pixels_mut(&mut self) -> &mut [PixelType];
+}
+
+macro_rules! impl_as_pixels {
+    ($Pixel:ident, $n:expr) => {
+        impl<T> AsPixels<$Pixel<T>> for [T] {
+            #[inline]
+            fn as_pixels(&self) -> &[$Pixel<T>] {
+                let len = self.len() / $n;
+                unsafe {
+                    slice::from_raw_parts(self.as_ptr() as *const $Pixel<T>, len)
+                }
+            }
+
+            #[inline]
+            fn as_pixels_mut(&mut self) -> &mut [$Pixel<T>] {
+                let len = self.len() / $n;
+                unsafe {
+                    slice::from_raw_parts_mut(self.as_mut_ptr() as *mut $Pixel<T>, len)
+                }
+            }
+        }
+    }
+}
+
+impl_as_pixels! {RGBA, 4}
+impl_as_pixels! {BGRA, 4}
+impl_as_pixels! {RGB, 3}
+impl_as_pixels! {BGR, 3}
+
+#[test]
+fn as_pixels_test() {
+    let bytes = [1u8,2,3,4, 5,6,7,8, 9];
+    let px: &[RGBA<u8>] = bytes.as_pixels();
+    assert_eq!(2, px.len());
+    assert_eq!(RGBA::new(1,2,3,4), px[0]);
+    assert_eq!(RGBA::new(5,6,7,8), px[1]);
+
+    let mut bytes = [1u8,2,3, 4,5,6];
+    {
+        let px: &mut [RGB<u8>] = bytes.as_pixels_mut();
+        px[1].r = 100;
+    }
+    assert_eq!([1,2,3, 100,5,6], bytes);
+}
@@ -0,0 +1,4 @@
+pub mod from_slice;
+pub mod pixel;
+pub mod rgb;
+pub mod rgba;
@@ -0,0 +1,41 @@
+use std;
+
+/// Transform components of a pixel, e.g. to change the color depth or type.
+pub trait ComponentMap<DestPixel, SrcComponent, DestComponent> {
+    /// Convert each component to a different type/value.
+    fn map<F>(&self, f: F) -> DestPixel
+    where
+        F: FnMut(SrcComponent) -> DestComponent;
+}
+
+/// Expose pixel components as a flat slice, e.g. `[R,G,B, R,G,B, ...]`.
+pub trait ComponentSlice<T> {
+    fn as_slice(&self) -> &[T];
+    fn as_mut_slice(&mut self) -> &mut [T];
+}
+
+/// Expose pixel components as raw bytes, e.g. for writing out to a file.
+///
+/// Has a default implementation for any type that already implements
+/// `ComponentSlice`, so implementors only need an empty `impl` block.
+pub trait ComponentBytes<T>: ComponentSlice<T> {
+    fn as_bytes(&self) -> &[u8] {
+        let slice = self.as_slice();
+        unsafe {
+            std::slice::from_raw_parts(
+                slice.as_ptr() as *const u8,
+                std::mem::size_of_val(slice),
+            )
+        }
+    }
+
+    fn as_mut_bytes(&mut self) -> &mut [u8] {
+        let slice = self.as_mut_slice();
+        unsafe {
+            std::slice::from_raw_parts_mut(
+                slice.as_mut_ptr() as *mut u8,
+                std::mem::size_of_val(slice),
+            )
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use std;
+use std::fmt;
+use super::pixel::*;
+use RGB;
+use RGBA;
+use alt::BGR;
+use alt::BGRA;
+
+impl<T: Clone> RGB<T> {
+    #[inline(always)]
+    pub fn new(r: T, g: T, b: T) -> Self {
+        Self {r,g,b}
+    }
+}
+
+macro_rules! impl_rgb {
+    ($RGB:ident, $RGBA:ident) => {
+        impl<T: Clone> $RGB<T> {
+            /// Iterate over all components (length=3)
+            #[inline(always)]
+            pub fn iter(&self) -> std::iter::Cloned<std::slice::Iter<'_, T>> {
+                self.as_slice().iter().cloned()
+            }
+
+            /// Combine with an alpha value into the matching RGBA-family pixel
+            #[inline(always)]
+            pub fn new_alpha<A>(&self, a: A) -> $RGBA<T, A> {
+                $RGBA {
+                    r: self.r.clone(),
+                    g: self.g.clone(),
+                    b: self.b.clone(),
+                    a,
+                }
+            }
+        }
+
+        impl<T: Copy, B> ComponentMap<$RGB<B>, T, B> for $RGB<T> {
+            #[inline(always)]
+            fn map<F>(&self, mut f: F) -> $RGB<B>
+            where
+                F: FnMut(T) -> B,
+            {
+                $RGB {
+                    r: f(self.r),
+                    g: f(self.g),
+                    b: f(self.b),
+                }
+            }
+        }
+
+        impl<T> ComponentSlice<T> for $RGB<T> {
+            #[inline(always)]
+            fn as_slice(&self) -> &[T] {
+                unsafe {
+                    std::slice::from_raw_parts(self as *const Self as *const T, 3)
+                }
+            }
+
+            #[inline(always)]
+            fn as_mut_slice(&mut self) -> &mut [T] {
+                unsafe {
+                    std::slice::from_raw_parts_mut(self as *mut Self as *mut T, 3)
+                }
+            }
+        }
+
+        impl<T> ComponentSlice<T> for [$RGB<T>] {
+            #[inline]
+            fn as_slice(&self) -> &[T] {
+                unsafe {
+                    std::slice::from_raw_parts(self.as_ptr() as *const _, self.len() * 3)
+                }
+            }
+            #[inline]
+            fn as_mut_slice(&mut self) -> &mut [T] {
+                unsafe {
+                    std::slice::from_raw_parts_mut(self.as_ptr() as *mut _, self.len() * 3)
+                }
+            }
+        }
+
+        #[cfg(not(feature = "as-bytes"))]
+        impl<T: Copy + Send + Sync + 'static> ComponentBytes<T> for [$RGB<T>] {}
+
+        #[cfg(feature = "as-bytes")]
+        impl<T: Copy + Send + Sync + 'static + bytemuck::Pod> ComponentBytes<T> for [$RGB<T>] {
+            #[inline]
+            fn as_bytes(&self) -> &[u8] {
+                bytemuck::cast_slice(self)
+            }
+            #[inline]
+            fn as_mut_bytes(&mut self) -> &mut [u8] {
+                bytemuck::cast_slice_mut(self)
+            }
+        }
+
+        // `$RGB<T>` is a plain array of `T` in memory, so it's byte-castable
+        // whenever `T` is, same as the `$RGBA<T>` homogeneous case.
+        #[cfg(feature = "as-bytes")]
+        unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for $RGB<T> {}
+
+        #[cfg(feature = "as-bytes")]
+        unsafe impl<T: bytemuck::Pod> bytemuck::Pod for $RGB<T> {}
+
+        // Serialized as a plain R,G,B sequence (matching `from_iter`'s
+        // canonical element order), same as RGBA/BGRA's 4-element form.
+        #[cfg(feature = "serde")]
+        impl<T: serde::Serialize> serde::Serialize for $RGB<T> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                use serde::ser::SerializeTuple;
+                let mut tup = serializer.serialize_tuple(3)?;
+                tup.serialize_element(&self.r)?;
+                tup.serialize_element(&self.g)?;
+                tup.serialize_element(&self.b)?;
+                tup.end()
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for $RGB<T> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct PixelVisitor<T>(std::marker::PhantomData<T>);
+
+                impl<'de, T: serde::Deserialize<'de>> serde::de::Visitor<'de> for PixelVisitor<T> {
+                    type Value = $RGB<T>;
+
+                    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                        write!(f, "a 3-element sequence of R,G,B components")
+                    }
+
+                    fn visit_seq<S: serde::de::SeqAccess<'de>>(self, mut seq: S) -> Result<Self::Value, S::Error> {
+                        let r = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                        let g = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+                        let b = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(2, &self))?;
+                        Ok($RGB { r, g, b })
+                    }
+                }
+
+                deserializer.deserialize_tuple(3, PixelVisitor(std::marker::PhantomData))
+            }
+        }
+    }
+}
+
+impl<T> std::iter::FromIterator<T> for RGB<T> {
+    #[inline(always)]
+    /// Takes exactly 3 elements from the iterator and creates a new instance.
+    /// Panics if there are fewer elements in the iterator.
+    fn from_iter<I: IntoIterator<Item = T>>(into_iter: I) -> Self {
+        let mut iter = into_iter.into_iter();
+        Self {
+            r: iter.next().unwrap(),
+            g: iter.next().unwrap(),
+            b: iter.next().unwrap(),
+        }
+    }
+}
+
+impl_rgb! {RGB, RGBA}
+impl_rgb! {BGR, BGRA}
+
+impl<T: fmt::Display> fmt::Display for RGB<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "rgb({},{},{})", self.r, self.g, self.b)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for BGR<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "bgr({},{},{})", self.r, self.g, self.b)
+    }
+}
+
+#[cfg(feature = "as-bytes")]
+#[test]
+fn rgb_bytemuck_test() {
+    let v = [RGB::new(1u8,2,3), RGB::new(4,5,6)];
+    assert_eq!(&[1,2,3,4,5,6], v.as_bytes());
+    let z: RGB<u8> = bytemuck::Zeroable::zeroed();
+    assert_eq!(z, RGB::new(0,0,0));
+}
+
+#[cfg(feature = "as-bytes")]
+#[test]
+fn bgr_bytemuck_test() {
+    let v = [BGR{b:1u8,g:2,r:3}, BGR{b:4,g:5,r:6}];
+    assert_eq!(&[1,2,3,4,5,6], v.as_bytes());
+    let z: BGR<u8> = bytemuck::Zeroable::zeroed();
+    assert_eq!(z, BGR{b:0,g:0,r:0});
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn rgb_serde_test() {
+    let px = RGB::new(1u8, 2, 3);
+    let json = serde_json::to_string(&px).unwrap();
+    assert_eq!("[1,2,3]", json);
+    assert_eq!(px, serde_json::from_str(&json).unwrap());
+}
+
+#[test]
+fn rgb_test() {
+    let neg = RGB::new(1,2,3i32).map(|x| -x);
+    assert_eq!(neg.r, -1);
+    assert_eq!(neg.g, -2);
+    assert_eq!(neg.b, -3);
+    assert_eq!(neg, neg.as_slice().iter().cloned().collect());
+    assert!(neg < RGB::new(0,0,0));
+
+    let v = [RGB::new(1u8,2,3), RGB::new(5,6,7)];
+    assert_eq!(&[1,2,3,5,6,7], v.as_bytes());
+}
+
+#[test]
+fn bgr_test() {
+    let neg = BGR{r:1,g:2,b:3i32}.map(|x| -x);
+    assert_eq!(neg.r, -1);
+    assert_eq!(neg.g, -2);
+    assert_eq!(neg.b, -3);
+    assert_eq!(&[-3,-2,-1], neg.as_slice());
+    assert!(neg < BGR{r:0,g:0,b:0});
+
+    let v = [BGR{b:1u8,g:2,r:3}, BGR{b:5,g:6,r:7}];
+    assert_eq!(&[1,2,3,5,6,7], v.as_bytes());
+}
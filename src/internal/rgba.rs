@@ -18,7 +18,7 @@ macro_rules! impl_rgba {
         impl<T: Clone> $RGBA<T> {
             /// Iterate over all components (length=4)
             #[inline(always)]
-            pub fn iter(&self) -> std::iter::Cloned<std::slice::Iter<T>> {
+            pub fn iter(&self) -> std::iter::Cloned<std::slice::Iter<'_, T>> {
                 self.as_slice().iter().cloned()
             }
         }
@@ -96,7 +96,70 @@ macro_rules! impl_rgba {
             }
         }
 
+        #[cfg(not(feature = "as-bytes"))]
         impl<T: Copy + Send + Sync + 'static> ComponentBytes<T> for [$RGBA<T>] {}
+
+        #[cfg(feature = "as-bytes")]
+        impl<T: Copy + Send + Sync + 'static + bytemuck::Pod> ComponentBytes<T> for [$RGBA<T>] {
+            #[inline]
+            fn as_bytes(&self) -> &[u8] {
+                bytemuck::cast_slice(self)
+            }
+            #[inline]
+            fn as_mut_bytes(&mut self) -> &mut [u8] {
+                bytemuck::cast_slice_mut(self)
+            }
+        }
+
+        // `$RGBA<T>` (the homogeneous `A = T` case) is a plain array of `T` in
+        // memory, so it's byte-castable whenever `T` is. The two-type
+        // `$RGBA<T, A>` form keeps the component-wise API above instead.
+        #[cfg(feature = "as-bytes")]
+        unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for $RGBA<T> {}
+
+        #[cfg(feature = "as-bytes")]
+        unsafe impl<T: bytemuck::Pod> bytemuck::Pod for $RGBA<T> {}
+
+        // Serialized as a plain R,G,B,A sequence (matching `from_iter`'s
+        // canonical element order) rather than a named-field map, so the
+        // encoded form stays as compact as the in-memory layout.
+        #[cfg(feature = "serde")]
+        impl<T: serde::Serialize, A: serde::Serialize> serde::Serialize for $RGBA<T, A> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                use serde::ser::SerializeTuple;
+                let mut tup = serializer.serialize_tuple(4)?;
+                tup.serialize_element(&self.r)?;
+                tup.serialize_element(&self.g)?;
+                tup.serialize_element(&self.b)?;
+                tup.serialize_element(&self.a)?;
+                tup.end()
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, T: serde::Deserialize<'de>, A: serde::Deserialize<'de>> serde::Deserialize<'de> for $RGBA<T, A> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct PixelVisitor<T, A>(std::marker::PhantomData<(T, A)>);
+
+                impl<'de, T: serde::Deserialize<'de>, A: serde::Deserialize<'de>> serde::de::Visitor<'de> for PixelVisitor<T, A> {
+                    type Value = $RGBA<T, A>;
+
+                    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                        write!(f, "a 4-element sequence of R,G,B,A components")
+                    }
+
+                    fn visit_seq<S: serde::de::SeqAccess<'de>>(self, mut seq: S) -> Result<Self::Value, S::Error> {
+                        let r = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                        let g = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+                        let b = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(2, &self))?;
+                        let a = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(3, &self))?;
+                        Ok($RGBA { r, g, b, a })
+                    }
+                }
+
+                deserializer.deserialize_tuple(4, PixelVisitor(std::marker::PhantomData))
+            }
+        }
     }
 }
 
@@ -130,6 +193,30 @@ impl<T: fmt::Display, A: fmt::Display> fmt::Display for BGRA<T, A> {
     }
 }
 
+#[cfg(feature = "as-bytes")]
+#[test]
+fn rgba_bytemuck_test() {
+    let v = [RGBA::new(1u8,2,3,4), RGBA::new(5,6,7,8)];
+    assert_eq!(&[1,2,3,4,5,6,7,8], v.as_bytes());
+    let z: RGBA<u8> = bytemuck::Zeroable::zeroed();
+    assert_eq!(z, RGBA::new(0,0,0,0));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn rgba_serde_test() {
+    let px = RGBA::new(1u8, 2, 3, 4);
+    let json = serde_json::to_string(&px).unwrap();
+    assert_eq!("[1,2,3,4]", json);
+    assert_eq!(px, serde_json::from_str(&json).unwrap());
+
+    // `A` distinct from `T` must round-trip too, not just the homogeneous case.
+    let px: RGBA<u8, f32> = RGBA { r: 1, g: 2, b: 3, a: 4.5 };
+    let json = serde_json::to_string(&px).unwrap();
+    assert_eq!("[1,2,3,4.5]", json);
+    assert_eq!(px, serde_json::from_str(&json).unwrap());
+}
+
 #[test]
 fn rgba_test() {
     let neg = RGBA::new(1,2,3i32,1000).map(|x| -x);
@@ -143,7 +230,7 @@ fn rgba_test() {
     assert_eq!(neg, neg.as_slice().iter().cloned().collect());
     assert!(neg < RGBA::new(0,0,0,0));
 
-    let neg = RGBA::new(1u8,2,3,4).map_rgb(|c| -(c as i16));
+    let neg: RGBA<i16, i16> = RGBA::new(1u8,2,3,4).map_rgb(|c| -(c as i16));
     assert_eq!(-1i16, neg.r);
     assert_eq!(4i16, neg.a);
 
@@ -155,7 +242,7 @@ fn rgba_test() {
     assert_eq!(4, px.rgb_mut().b);
     assert_eq!(100, px.a);
 
-    let v = vec![RGBA::new(1u8,2,3,4), RGBA::new(5,6,7,8)];
+    let v = [RGBA::new(1u8,2,3,4), RGBA::new(5,6,7,8)];
     assert_eq!(&[1,2,3,4,5,6,7,8], v.as_bytes());
 }
 
@@ -172,7 +259,7 @@ fn bgra_test() {
     assert_eq!(&[-3,-2,-1,-1000], neg.as_slice());
     assert!(neg < BGRA{r:0,g:0,b:0,a:0});
 
-    let neg = BGRA{r:1u8,g:2u8,b:3u8,a:4u8}.map_rgb(|c| -(c as i16));
+    let neg: BGRA<i16, i16> = BGRA{r:1u8,g:2u8,b:3u8,a:4u8}.map_rgb(|c| -(c as i16));
     assert_eq!(-1i16, neg.r);
     assert_eq!(4i16, neg.a);
 
@@ -184,6 +271,6 @@ fn bgra_test() {
     assert_eq!(4, px.rgb_mut().b);
     assert_eq!(100, px.a);
 
-    let v = vec![BGRA{b:1u8,g:2,r:3,a:4}, BGRA{b:5,g:6,r:7,a:8}];
+    let v = [BGRA{b:1u8,g:2,r:3,a:4}, BGRA{b:5,g:6,r:7,a:8}];
     assert_eq!(&[1,2,3,4,5,6,7,8], v.as_bytes());
 }
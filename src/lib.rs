@@ -0,0 +1,38 @@
+//! `RGB`/`RGBA` pixel types for sharing image data between crates, plus
+//! conversions, arithmetic, and (de)serialization helpers.
+
+#[cfg(feature = "as-bytes")]
+extern crate bytemuck;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
+
+mod internal;
+pub mod alt;
+pub mod ops;
+
+pub use internal::from_slice::AsPixels;
+pub use internal::pixel::{ComponentBytes, ComponentMap, ComponentSlice};
+
+/// An RGB pixel: red, green, blue, in that order in memory.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct RGB<T> {
+    pub r: T,
+    pub g: T,
+    pub b: T,
+}
+
+/// An RGBA pixel: red, green, blue, alpha, in that order in memory.
+///
+/// `A` defaults to `T`, but can be a distinct type for pixels whose alpha
+/// channel needs different precision than the color channels.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct RGBA<T, A = T> {
+    pub r: T,
+    pub g: T,
+    pub b: T,
+    pub a: A,
+}
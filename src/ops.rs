@@ -0,0 +1,207 @@
+//! Arithmetic over whole pixels: `px + px`, `px * 2`, `iter().sum()`, etc.
+//!
+//! Operations apply per-channel, including alpha for the alpha-carrying
+//! types. Scalar operands are broadcast to every channel.
+
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use RGB;
+use RGBA;
+use alt::BGR;
+use alt::BGRA;
+
+macro_rules! impl_ops {
+    ($name:ident, $($field:ident),+) => {
+        impl<T: Add<Output = T>> Add for $name<T> {
+            type Output = $name<T>;
+            #[inline(always)]
+            fn add(self, other: $name<T>) -> Self::Output {
+                $name { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl<T: Add<Output = T> + Copy> Add<T> for $name<T> {
+            type Output = $name<T>;
+            #[inline(always)]
+            fn add(self, r: T) -> Self::Output {
+                $name { $($field: self.$field + r),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $name<T> {
+            type Output = $name<T>;
+            #[inline(always)]
+            fn sub(self, other: $name<T>) -> Self::Output {
+                $name { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T> + Copy> Sub<T> for $name<T> {
+            type Output = $name<T>;
+            #[inline(always)]
+            fn sub(self, r: T) -> Self::Output {
+                $name { $($field: self.$field - r),+ }
+            }
+        }
+
+        impl<T: Mul<Output = T>> Mul for $name<T> {
+            type Output = $name<T>;
+            #[inline(always)]
+            fn mul(self, other: $name<T>) -> Self::Output {
+                $name { $($field: self.$field * other.$field),+ }
+            }
+        }
+
+        impl<T: Mul<Output = T> + Copy> Mul<T> for $name<T> {
+            type Output = $name<T>;
+            #[inline(always)]
+            fn mul(self, r: T) -> Self::Output {
+                $name { $($field: self.$field * r),+ }
+            }
+        }
+
+        impl<T: Div<Output = T>> Div for $name<T> {
+            type Output = $name<T>;
+            #[inline(always)]
+            fn div(self, other: $name<T>) -> Self::Output {
+                $name { $($field: self.$field / other.$field),+ }
+            }
+        }
+
+        impl<T: Div<Output = T> + Copy> Div<T> for $name<T> {
+            type Output = $name<T>;
+            #[inline(always)]
+            fn div(self, r: T) -> Self::Output {
+                $name { $($field: self.$field / r),+ }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $name<T> {
+            #[inline(always)]
+            fn add_assign(&mut self, other: $name<T>) {
+                $(self.$field += other.$field;)+
+            }
+        }
+
+        impl<T: AddAssign + Copy> AddAssign<T> for $name<T> {
+            #[inline(always)]
+            fn add_assign(&mut self, r: T) {
+                $(self.$field += r;)+
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $name<T> {
+            #[inline(always)]
+            fn sub_assign(&mut self, other: $name<T>) {
+                $(self.$field -= other.$field;)+
+            }
+        }
+
+        impl<T: SubAssign + Copy> SubAssign<T> for $name<T> {
+            #[inline(always)]
+            fn sub_assign(&mut self, r: T) {
+                $(self.$field -= r;)+
+            }
+        }
+
+        impl<T: MulAssign> MulAssign for $name<T> {
+            #[inline(always)]
+            fn mul_assign(&mut self, other: $name<T>) {
+                $(self.$field *= other.$field;)+
+            }
+        }
+
+        impl<T: MulAssign + Copy> MulAssign<T> for $name<T> {
+            #[inline(always)]
+            fn mul_assign(&mut self, r: T) {
+                $(self.$field *= r;)+
+            }
+        }
+
+        impl<T: DivAssign> DivAssign for $name<T> {
+            #[inline(always)]
+            fn div_assign(&mut self, other: $name<T>) {
+                $(self.$field /= other.$field;)+
+            }
+        }
+
+        impl<T: DivAssign + Copy> DivAssign<T> for $name<T> {
+            #[inline(always)]
+            fn div_assign(&mut self, r: T) {
+                $(self.$field /= r;)+
+            }
+        }
+
+        impl<T: Add<Output = T> + Default> Sum for $name<T> {
+            #[inline]
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold($name { $($field: T::default()),+ }, |a, b| a + b)
+            }
+        }
+
+        impl<'a, T: Add<Output = T> + Default + Copy> Sum<&'a $name<T>> for $name<T> {
+            #[inline]
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.fold($name { $($field: T::default()),+ }, |a, &b| a + b)
+            }
+        }
+    }
+}
+
+impl_ops! {RGB, r, g, b}
+impl_ops! {BGR, b, g, r}
+impl_ops! {RGBA, r, g, b, a}
+impl_ops! {BGRA, b, g, r, a}
+
+#[test]
+fn rgb_ops_test() {
+    let a = RGB::new(1, 2, 3);
+    let b = RGB::new(4, 5, 6);
+    assert_eq!(RGB::new(5, 7, 9), a + b);
+    assert_eq!(RGB::new(3, 3, 3), b - a);
+    assert_eq!(RGB::new(2, 4, 6), a * 2);
+    assert_eq!(RGB::new(2, 4, 6), a + a);
+
+    let mut c = a;
+    c += b;
+    assert_eq!(RGB::new(5, 7, 9), c);
+    c *= 2;
+    assert_eq!(RGB::new(10, 14, 18), c);
+
+    let v = vec![a, b];
+    assert_eq!(RGB::new(5, 7, 9), v.iter().sum());
+    assert_eq!(RGB::new(5, 7, 9), v.into_iter().sum());
+}
+
+#[test]
+fn rgba_ops_test() {
+    let a = RGBA::new(1, 2, 3, 4);
+    let b = RGBA::new(4, 5, 6, 7);
+    assert_eq!(RGBA::new(5, 7, 9, 11), a + b);
+    assert_eq!(RGBA::new(2, 4, 6, 8), a * 2);
+}
+
+#[test]
+fn bgr_ops_test() {
+    let a = BGR{b:1,g:2,r:3};
+    let b = BGR{b:4,g:5,r:6};
+    assert_eq!(BGR{b:5,g:7,r:9}, a + b);
+    assert_eq!(BGR{b:3,g:3,r:3}, b - a);
+    assert_eq!(BGR{b:2,g:4,r:6}, a * 2);
+
+    let mut c = a;
+    c += b;
+    assert_eq!(BGR{b:5,g:7,r:9}, c);
+
+    let v = vec![a, b];
+    assert_eq!(BGR{b:5,g:7,r:9}, v.iter().sum());
+    assert_eq!(BGR{b:5,g:7,r:9}, v.into_iter().sum());
+}
+
+#[test]
+fn bgra_ops_test() {
+    let a = BGRA{b:1,g:2,r:3,a:4};
+    let b = BGRA{b:4,g:5,r:6,a:7};
+    assert_eq!(BGRA{b:5,g:7,r:9,a:11}, a + b);
+    assert_eq!(BGRA{b:2,g:4,r:6,a:8}, a * 2);
+}